@@ -1,10 +1,17 @@
 pub mod card;
+pub mod count;
+pub mod log;
+pub mod shoe;
+pub mod strategy;
 
 use card::*;
-use rand::seq::SliceRandom;
+use log::{LogEvent, Recipient, SessionRecorder};
+use serde::{Deserialize, Serialize};
+use shoe::Shoe;
 use std::{
     fmt::Display,
     io::{stdout, Stdout, Write},
+    path::PathBuf,
 };
 use termion::{
     color::*,
@@ -35,11 +42,12 @@ pub enum Input {
     Down,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Choice {
     Hit,
     Stand,
-    // DoubleDown,
+    DoubleDown,
+    Split,
     Surrender,
 }
 
@@ -49,69 +57,153 @@ impl Display for Choice {
         match self {
             Hit => write!(f, "Hit"),
             Stand => write!(f, "Stand"),
+            DoubleDown => write!(f, "Double Down"),
+            Split => write!(f, "Split"),
             Surrender => write!(f, "Surrender"),
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GameResult {
     Win,
+    /// A two-card 21 dealt straight from the shoe, paid 3:2 instead of the
+    /// even money an ordinary `Win` pays.
+    Blackjack,
     Lose,
     Tie,
+    Surrendered,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Stage {
     First,
     Second,
     Third,
+    Fourth,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum State {
+    Betting(u32),
     Starting(Stage),
-    Presenting,
+    Insurance(bool),
     Selecting(Choice),
     Standing,
-    GameOver(GameResult),
+    GameOver(Vec<GameResult>),
+}
+
+/// Tunable rules for a `Game`, gathered here so new knobs (trainer mode,
+/// hint toggles, ...) have one place to land instead of growing the
+/// `Game::new` argument list.
+#[derive(Debug, Clone)]
+pub struct GameOptions {
+    pub decks: u8,
+    pub penetration: f32,
+    pub jokers: WithOrWithoutJokers,
+    /// Shows the basic-strategy advisor's suggested move next to the
+    /// player's choices. Turn off for unassisted play.
+    pub show_strategy_hint: bool,
+    /// Shows the Hi-Lo running/true count, turning the game into a
+    /// counting trainer.
+    pub trainer_mode: bool,
+    /// Bankroll the player starts a session with.
+    pub starting_bankroll: i64,
+    /// Smallest bet the player can place.
+    pub min_bet: u32,
+    /// How much [`Input::Up`]/[`Input::Down`] move the bet by in the
+    /// betting screen.
+    pub bet_step: u32,
+    /// Where to write a newline-delimited JSON log of the session when the
+    /// `Game` is dropped. `None` disables recording entirely.
+    pub record_path: Option<PathBuf>,
+}
+
+impl Default for GameOptions {
+    fn default() -> Self {
+        GameOptions {
+            decks: 6,
+            penetration: 0.75,
+            jokers: WithOrWithoutJokers::Without,
+            show_strategy_hint: true,
+            trainer_mode: false,
+            starting_bankroll: 1000,
+            min_bet: 10,
+            bet_step: 10,
+            record_path: None,
+        }
+    }
 }
 
 pub struct Game {
     terminal: Terminal,
-    deck: Vec<Card>,
+    options: GameOptions,
+    shoe: Shoe,
     state: State,
-    player: Hand,
+    player_hands: Vec<Hand>,
+    active_hand: usize,
+    bets: Vec<u32>,
+    hand_results: Vec<Option<GameResult>>,
     dealer: Hand,
+    running_count: i32,
+    bankroll: i64,
+    bet: u32,
+    insurance_bet: Option<u32>,
+    recorder: SessionRecorder,
+    /// The Hi-Lo tag of the dealer's hole card, held back from
+    /// `running_count` until the card is actually revealed so the
+    /// trainer-mode count never reflects a card the player can't see.
+    hole_card_count: Option<i32>,
 }
 
 impl Game {
     pub fn new() -> Game {
-        use Stage::*;
-        use State::*;
+        Game::with_options(GameOptions::default())
+    }
+
+    pub fn with_options(options: GameOptions) -> Game {
         let mut terminal = cursor::HideCursor::from(stdout().into_raw_mode().unwrap());
         write!(terminal, "{}", screen::ToAlternateScreen).unwrap();
+        let bet = options.min_bet;
+        let bankroll = options.starting_bankroll;
+        let recorder = SessionRecorder::new(options.record_path.clone());
         Game {
             terminal: Terminal { terminal },
-            deck: pack(),
-            state: Starting(First),
-            player: Hand::new(),
+            shoe: Shoe::new(options.decks, options.penetration, options.jokers),
+            options,
+            state: State::Betting(bet),
+            player_hands: vec![Hand::new()],
+            active_hand: 0,
+            bets: vec![bet],
+            hand_results: vec![None],
             dealer: Hand::new(),
+            running_count: 0,
+            bankroll,
+            bet,
+            insurance_bet: None,
+            recorder,
+            hole_card_count: None,
         }
     }
 
     pub fn update(&mut self, input: Input) {
         use Choice::*;
-        use GameResult::*;
         use Input::*;
         use Stage::*;
         use State::*;
         self.state = {
             match &self.state {
+                Betting(amount) => match input {
+                    Up => Betting(self.raise_bet(*amount)),
+                    Down => Betting(self.lower_bet(*amount)),
+                    Continue => {
+                        self.start_round(*amount);
+                        Starting(First)
+                    }
+                },
                 Starting(stage) if input == Continue => match stage {
                     First => {
-                        self.shuffle();
-                        self.deal_player();
+                        self.deal_active_hand();
                         Starting(Second)
                     }
                     Second => {
@@ -119,72 +211,94 @@ impl Game {
                         Starting(Third)
                     }
                     Third => {
-                        self.deal_player();
-                        Selecting(Hit)
+                        self.deal_active_hand();
+                        Starting(Fourth)
+                    }
+                    Fourth => {
+                        self.deal_dealer_hole_card();
+                        if self.dealer_shows_ace() {
+                            Insurance(false)
+                        } else {
+                            self.resolve_naturals_or_continue()
+                        }
+                    }
+                },
+                Insurance(take) if input == Continue => {
+                    if *take {
+                        self.buy_insurance();
                     }
+                    self.resolve_naturals_or_continue()
+                }
+                Insurance(take) => match input {
+                    Up | Down => Insurance(!take),
+                    Continue => unreachable!(),
                 },
                 Selecting(choice) => match (input, choice) {
                     (Continue, Hit) => {
-                        self.deal_player();
-                        if self.player.points() > 21 {
-                            GameOver(Lose)
+                        self.deal_active_hand();
+                        if self.active_hand_points() > 21 {
+                            self.hand_results[self.active_hand] = Some(GameResult::Lose);
+                            self.advance_after_hand()
                         } else {
                             Selecting(Hit)
                         }
                     }
-                    (Continue, Stand) => {
-                        self.deal_dealer();
-                        if self.dealer.points() > 21 {
-                            GameOver(Win)
-                        } else if self.dealer.points() < 17 {
-                            Standing
-                        } else if self.dealer.points() > self.player.points() {
-                            GameOver(Lose)
-                        } else if self.dealer.points() < self.player.points() {
-                            GameOver(Win)
-                        } else {
-                            GameOver(Tie)
+                    (Continue, Stand) => self.advance_after_hand(),
+                    (Continue, DoubleDown) => {
+                        self.double_down();
+                        self.deal_active_hand();
+                        if self.active_hand_points() > 21 {
+                            self.hand_results[self.active_hand] = Some(GameResult::Lose);
                         }
+                        self.advance_after_hand()
+                    }
+                    (Continue, Split) => {
+                        self.split_active_hand();
+                        Selecting(Hit)
+                    }
+                    (Continue, Surrender) => {
+                        self.hand_results[self.active_hand] = Some(GameResult::Surrendered);
+                        self.advance_after_hand()
                     }
-                    (Continue, Surrender) => GameOver(Lose),
-
-                    (Up, Hit) => Selecting(Surrender),
-                    (Up, Stand) => Selecting(Hit),
-                    (Up, Surrender) => Selecting(Stand),
 
-                    (Down, Hit) => Selecting(Stand),
-                    (Down, Stand) => Selecting(Surrender),
-                    (Down, Surrender) => Selecting(Hit),
+                    (Up, current) => self.cycle_choice(current, 1),
+                    (Down, current) => self.cycle_choice(current, -1),
                 },
-                Presenting => Selecting(Hit),
                 Standing if input == Continue => {
-                    self.deal_dealer();
-                    if self.dealer.points() > 21 {
-                        GameOver(Win)
-                    } else if self.dealer.points() < 17 {
+                    if self.dealer.points() < 17 {
+                        self.deal_dealer();
+                    }
+                    if self.dealer.points() < 17 {
                         Standing
-                    } else if self.dealer.points() > self.player.points() {
-                        GameOver(Lose)
-                    } else if self.dealer.points() < self.player.points() {
-                        GameOver(Win)
                     } else {
-                        GameOver(Tie)
+                        self.finish_vs_dealer()
                     }
                 }
                 GameOver(_) if input == Continue => {
-                    self.deck.append(&mut self.dealer.cards);
-                    self.deck.append(&mut self.player.cards);
-                    Starting(First)
+                    for hand in &mut self.player_hands {
+                        self.shoe.discard(&mut hand.cards);
+                    }
+                    self.shoe.discard(&mut self.dealer.cards);
+                    if self.shoe.reshuffle_if_needed() {
+                        self.running_count = 0;
+                    }
+                    self.player_hands = vec![Hand::new()];
+                    self.dealer = Hand::new();
+                    let max_bet = self.options.min_bet.max(self.bankroll.max(0) as u32);
+                    Betting(self.bet.clamp(self.options.min_bet, max_bet))
                 }
                 _ => self.state.clone(),
             }
-        }
+        };
+        self.recorder.record(LogEvent::StateChanged {
+            state: self.state.clone(),
+            dealer: self.dealer.clone(),
+            player_hands: self.player_hands.clone(),
+        });
     }
 
     pub fn render(&mut self) {
-        use Choice::*;
         use GameResult::*;
-        use Stage::*;
         use State::*;
         self.terminal.clear();
 
@@ -198,45 +312,136 @@ impl Game {
         self.terminal.println("");
 
         self.dealer.render(&mut self.terminal, "Dealer");
-        self.player.render(&mut self.terminal, "You");
+        for (i, hand) in self.player_hands.iter().enumerate() {
+            let label = if self.player_hands.len() > 1 {
+                format!(
+                    "You{} (hand {}/{})",
+                    if i == self.active_hand { " *" } else { "" },
+                    i + 1,
+                    self.player_hands.len()
+                )
+            } else {
+                "You".to_string()
+            };
+            hand.render(&mut self.terminal, &label);
+        }
+
+        self.terminal.println(format!(
+            "{}Bankroll: {}{}  Bet: {}",
+            Fg(Yellow),
+            self.bankroll,
+            Fg(Reset),
+            self.bet
+        ));
+        self.terminal.println(format!(
+            "Shoe: {} cards left (~{:.1} decks)",
+            self.shoe.cards_remaining(),
+            self.shoe.decks_remaining()
+        ));
+        if self.options.trainer_mode {
+            self.terminal.println(format!(
+                "Count: running {} / true {}",
+                self.running_count,
+                count::true_count(self.running_count, self.shoe.cards_remaining())
+            ));
+        }
+        self.terminal.println("");
 
         match &self.state {
+            Betting(amount) => {
+                self.terminal.println(format!("Bet: {}", amount));
+                self.terminal
+                    .println("[UP/DOWN] Adjust bet   [SPACE/ENTER] Deal");
+            }
+            Insurance(take) => {
+                self.terminal
+                    .println("Dealer shows an Ace. Buy insurance?");
+                self.terminal
+                    .println(if *take { "> Yes" } else { "- Yes" });
+                self.terminal
+                    .println(if *take { "- No" } else { "> No" });
+                self.terminal.println("[SPACE / ENTER] Confirm");
+            }
             Selecting(c) => {
-                let select = |it: Choice| {
-                    format!(
+                let choices = self.legal_choices();
+                for choice in &choices {
+                    self.terminal.println(format!(
                         "{} {}{}",
-                        if it == *c {
+                        if choice == c {
                             String::from(LightBlue.fg_str()) + ">"
                         } else {
                             "-".to_string()
                         },
-                        it,
+                        choice,
+                        Fg(Reset)
+                    ));
+                }
+
+                if self.options.show_strategy_hint {
+                    let dealer_upcard = self.dealer.cards.first().expect(
+                        "dealer always has an upcard by the time the player is selecting",
+                    );
+                    let advice = strategy::decide(
+                        &self.player_hands[self.active_hand],
+                        dealer_upcard,
+                        choices.contains(&Choice::DoubleDown),
+                        choices.contains(&Choice::Split),
+                    );
+                    self.terminal.println(format!(
+                        "{}Hint: {}{}",
+                        Fg(LightGreen),
+                        advice,
                         Fg(Reset)
-                    )
-                };
-                self.terminal.println(select(Hit));
-                self.terminal.println(select(Stand));
-                self.terminal.println(select(Surrender));
+                    ));
+                }
             }
-            Starting(First) => {
+            Starting(Stage::First) => {
                 self.terminal.println("Welcome to Blackjack!");
                 self.terminal.println("[SPACE / ENTER] Start");
             }
-            Presenting | Standing | Starting(_) => {
+            Standing | Starting(_) => {
                 self.terminal.println("[SPACE / ENTER] Continue");
             }
-            GameOver(result) => {
-                match result {
-                    Win => self
-                        .terminal
-                        .println(format!("{}You win!{}", Fg(Green), Fg(Reset))),
-                    Lose => self
-                        .terminal
-                        .println(format!("{}You lose!{}", Fg(Red), Fg(Reset))),
-                    Tie => self
-                        .terminal
-                        .println(format!("{}It's a tie!{}", Fg(Yellow), Fg(Reset))),
-                };
+            GameOver(results) => {
+                for (i, result) in results.iter().enumerate() {
+                    let prefix = if results.len() > 1 {
+                        format!("Hand {}: ", i + 1)
+                    } else {
+                        String::new()
+                    };
+                    match result {
+                        Win => self.terminal.println(format!(
+                            "{}{}You win!{}",
+                            prefix,
+                            Fg(Green),
+                            Fg(Reset)
+                        )),
+                        Blackjack => self.terminal.println(format!(
+                            "{}{}Blackjack! You win 3:2!{}",
+                            prefix,
+                            Fg(Green),
+                            Fg(Reset)
+                        )),
+                        Lose => self.terminal.println(format!(
+                            "{}{}You lose!{}",
+                            prefix,
+                            Fg(Red),
+                            Fg(Reset)
+                        )),
+                        Tie => self.terminal.println(format!(
+                            "{}{}It's a tie!{}",
+                            prefix,
+                            Fg(Yellow),
+                            Fg(Reset)
+                        )),
+                        Surrendered => self.terminal.println(format!(
+                            "{}{}You surrendered.{}",
+                            prefix,
+                            Fg(Yellow),
+                            Fg(Reset)
+                        )),
+                    };
+                }
                 self.terminal.println("[SPACE / ENTER] Play again");
                 self.terminal.println("[ESC / Q] Quit");
             }
@@ -244,16 +449,258 @@ impl Game {
         self.terminal.terminal.flush().unwrap();
     }
 
-    fn shuffle(&mut self) {
-        self.deck.shuffle(&mut rand::thread_rng());
+    fn raise_bet(&self, amount: u32) -> u32 {
+        let max_bet = self.options.min_bet.max(self.bankroll.max(0) as u32);
+        (amount + self.options.bet_step).min(max_bet)
+    }
+
+    fn lower_bet(&self, amount: u32) -> u32 {
+        amount
+            .saturating_sub(self.options.bet_step)
+            .max(self.options.min_bet)
+    }
+
+    fn start_round(&mut self, bet: u32) {
+        self.bet = bet;
+        self.bankroll -= bet as i64;
+        self.player_hands = vec![Hand::new()];
+        self.bets = vec![bet];
+        self.hand_results = vec![None];
+        self.active_hand = 0;
+        self.insurance_bet = None;
+        self.dealer = Hand::new();
+    }
+
+    fn dealer_shows_ace(&self) -> bool {
+        matches!(self.dealer.cards.first(), Some(Card::Standard(CardNumber::Ace, _)))
+    }
+
+    /// Whether the dealer's upcard is worth peeking under for blackjack:
+    /// only an ace or a ten-value card can complete a two-card 21.
+    fn dealer_shows_peek_card(&self) -> bool {
+        use CardNumber::*;
+        matches!(
+            self.dealer.cards.first(),
+            Some(Card::Standard(Ace | Ten | Jack | Queen | King, _))
+        )
+    }
+
+    fn dealer_has_natural(&self) -> bool {
+        self.dealer.cards.len() == 2 && self.dealer.points() == 21
     }
 
-    fn deal_player(&mut self) {
-        self.player.add_card(self.deck.pop().unwrap());
+    fn buy_insurance(&mut self) {
+        let insurance = self.bet / 2;
+        self.bankroll -= insurance as i64;
+        self.insurance_bet = Some(insurance);
+    }
+
+    /// Peeks for a dealer natural when the upcard allows one, and checks
+    /// the player's hand for a natural of their own, settling the round
+    /// immediately if either is found. Otherwise the player's turn
+    /// begins as normal.
+    fn resolve_naturals_or_continue(&mut self) -> State {
+        let dealer_natural = self.dealer_shows_peek_card() && self.dealer_has_natural();
+        if dealer_natural {
+            self.reveal_hole_card();
+        }
+
+        let player_natural = self.player_hands[0].points() == 21;
+        if dealer_natural || player_natural {
+            self.settle_naturals(dealer_natural, player_natural)
+        } else {
+            State::Selecting(Choice::Hit)
+        }
+    }
+
+    fn settle_naturals(&mut self, dealer_natural: bool, player_natural: bool) -> State {
+        use GameResult::*;
+        self.hand_results[0] = Some(if dealer_natural && player_natural {
+            Tie
+        } else if dealer_natural {
+            Lose
+        } else {
+            Blackjack
+        });
+        self.settle()
+    }
+
+    fn active_hand_points(&self) -> u8 {
+        self.player_hands[self.active_hand].points()
+    }
+
+    /// The choices currently open to the player on the active hand:
+    /// double down and split are only offered on an untouched two-card
+    /// hand the player can still afford to match.
+    fn legal_choices(&self) -> Vec<Choice> {
+        use Choice::*;
+        let hand = &self.player_hands[self.active_hand];
+        let affordable = self.bankroll >= self.bets[self.active_hand] as i64;
+        let mut choices = vec![Hit, Stand];
+        if hand.cards.len() == 2 && affordable {
+            choices.push(DoubleDown);
+            if strategy::is_pair(hand) {
+                choices.push(Split);
+            }
+        }
+        choices.push(Surrender);
+        choices
+    }
+
+    fn cycle_choice(&self, current: &Choice, direction: i32) -> State {
+        let choices = self.legal_choices();
+        let len = choices.len() as i32;
+        let current_index = choices.iter().position(|c| c == current).unwrap_or(0) as i32;
+        let next_index = (current_index + direction).rem_euclid(len);
+        State::Selecting(choices[next_index as usize].clone())
+    }
+
+    fn double_down(&mut self) {
+        let bet = self.bets[self.active_hand];
+        self.bankroll -= bet as i64;
+        self.bets[self.active_hand] += bet;
+    }
+
+    fn split_active_hand(&mut self) {
+        let bet = self.bets[self.active_hand];
+        self.bankroll -= bet as i64;
+
+        let second_card = self.player_hands[self.active_hand]
+            .cards
+            .pop()
+            .expect("split is only offered on a two-card hand");
+        let mut new_hand = Hand::new();
+        new_hand.add_card(second_card);
+
+        self.player_hands.insert(self.active_hand + 1, new_hand);
+        self.bets.insert(self.active_hand + 1, bet);
+        self.hand_results.insert(self.active_hand + 1, None);
+
+        self.deal_active_hand();
+        self.active_hand += 1;
+        self.deal_active_hand();
+        self.active_hand -= 1;
+    }
+
+    /// Moves on to the next split hand, starts the dealer's turn, or
+    /// settles the round immediately if every hand is already decided
+    /// (all busted or surrendered, so there's nothing left for the
+    /// dealer to play for).
+    fn advance_after_hand(&mut self) -> State {
+        if self.active_hand + 1 < self.player_hands.len() {
+            self.active_hand += 1;
+            State::Selecting(Choice::Hit)
+        } else {
+            self.reveal_hole_card();
+            if self.hand_results.iter().all(Option::is_some) {
+                self.settle()
+            } else if self.dealer.points() >= 17 {
+                self.finish_vs_dealer()
+            } else {
+                State::Standing
+            }
+        }
+    }
+
+    fn finish_vs_dealer(&mut self) -> State {
+        use GameResult::*;
+        let dealer_points = self.dealer.points();
+        let dealer_busted = dealer_points > 21;
+        for i in 0..self.player_hands.len() {
+            if self.hand_results[i].is_none() {
+                let points = self.player_hands[i].points();
+                self.hand_results[i] = Some(if dealer_busted {
+                    Win
+                } else if dealer_points > points {
+                    Lose
+                } else if dealer_points < points {
+                    Win
+                } else {
+                    Tie
+                });
+            }
+        }
+        self.settle()
+    }
+
+    fn settle(&mut self) -> State {
+        use GameResult::*;
+        self.reveal_hole_card();
+        for (i, result) in self.hand_results.iter().enumerate() {
+            let bet = self.bets[i] as i64;
+            self.bankroll += match result.as_ref().expect("every hand is decided by settle") {
+                Win => bet * 2,
+                Blackjack => bet + bet * 3 / 2,
+                Tie => bet,
+                Lose => 0,
+                Surrendered => bet / 2,
+            };
+        }
+        if let Some(insurance) = self.insurance_bet {
+            if self.dealer.cards.len() == 2 && self.dealer.points() == 21 {
+                self.bankroll += insurance as i64 * 3;
+            }
+        }
+        State::GameOver(
+            self.hand_results
+                .iter()
+                .map(|r| r.clone().expect("every hand is decided by settle"))
+                .collect(),
+        )
+    }
+
+    fn deal_active_hand(&mut self) {
+        let card = self.draw_card();
+        self.running_count += count::hi_lo_value(&card);
+        self.recorder.record(LogEvent::CardDealt {
+            recipient: Recipient::Player(self.active_hand),
+            card: card.clone(),
+        });
+        self.player_hands[self.active_hand].add_card(card);
     }
 
     fn deal_dealer(&mut self) {
-        self.dealer.add_card(self.deck.pop().unwrap());
+        let card = self.draw_card();
+        self.running_count += count::hi_lo_value(&card);
+        self.recorder
+            .record(LogEvent::CardDealt { recipient: Recipient::Dealer, card: card.clone() });
+        self.dealer.add_card(card);
+    }
+
+    /// Deals the dealer's hole card face down. Its Hi-Lo tag is held back
+    /// in `hole_card_count` rather than added to `running_count` right
+    /// away, since the player can't see it yet; `reveal_hole_card` applies
+    /// the delta once the card is actually shown.
+    fn deal_dealer_hole_card(&mut self) {
+        let card = self.draw_card();
+        self.hole_card_count = Some(count::hi_lo_value(&card));
+        self.recorder
+            .record(LogEvent::CardDealt { recipient: Recipient::Dealer, card: card.clone() });
+        self.dealer.add_card(card);
+        self.dealer.hide_hole_card();
+    }
+
+    /// Reveals the dealer's hole card and, if its Hi-Lo tag was held back
+    /// by `deal_dealer_hole_card`, folds it into `running_count` now that
+    /// the player can see it.
+    fn reveal_hole_card(&mut self) {
+        self.dealer.reveal_hole_card();
+        if let Some(value) = self.hole_card_count.take() {
+            self.running_count += value;
+        }
+    }
+
+    /// Draws the next playable card from the shoe, discarding and
+    /// redrawing any jokers: blackjack has no use for them, but the shoe
+    /// itself stays reusable by games that do.
+    fn draw_card(&mut self) -> Card {
+        loop {
+            let card = self.shoe.deal();
+            if card != Card::Joker {
+                return card;
+            }
+            self.shoe.discard_one(card);
+        }
     }
 }
 
@@ -262,5 +709,6 @@ impl Drop for Game {
         self.terminal.clear();
         write!(self.terminal.terminal, "{}", screen::ToMainScreen).unwrap();
         self.terminal.terminal.flush().unwrap();
+        self.recorder.flush();
     }
 }
@@ -1,11 +1,12 @@
 use std::fmt::Display;
+use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 use termion::color::*;
 
 use super::Terminal;
 
-#[derive(Debug, EnumIter, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, EnumIter, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CardNumber {
     Ace,
     Two,
@@ -22,7 +23,7 @@ pub enum CardNumber {
     King,
 }
 
-#[derive(Debug, EnumIter, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, EnumIter, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Suit {
     Clubs,
     Diamonds,
@@ -30,8 +31,22 @@ pub enum Suit {
     Hearts,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Card(pub CardNumber, pub Suit);
+/// A card may be a standard ranked-and-suited card, or a joker. Jokers have
+/// no rank or suit, so `pack` only produces them when explicitly asked to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Card {
+    Standard(CardNumber, Suit),
+    Joker,
+}
+
+/// How many jokers `pack` adds when built `WithOrWithoutJokers::With`.
+pub const NUM_JOKERS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WithOrWithoutJokers {
+    With,
+    Without,
+}
 
 impl Display for CardNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -66,34 +81,72 @@ impl Display for Suit {
     }
 }
 
-impl Display for Card {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// How many lines tall a rendered card box is; `render_row` uses this to
+/// lay several cards' boxes out side by side.
+const CARD_HEIGHT: usize = 5;
+
+impl Card {
+    /// Renders this card as a `CARD_HEIGHT`-line box: rank in the top-left
+    /// and bottom-right corners, suit centered, colored red for diamonds
+    /// and hearts and white for clubs and spades.
+    fn render_lines(&self) -> Vec<String> {
         use Suit::*;
-        let Card(card_number, suit) = self;
-        write!(
-            f,
-            "{}{} {}{}",
-            match suit {
-                Diamonds => format!("{}", Fg(Red)),
-                Clubs => format!("{}", Fg(White)),
-                Hearts => format!("{}", Fg(Red)),
-                Spades => format!("{}", Fg(White)),
-            },
-            card_number,
-            suit,
-            Fg(Reset),
-        )
+        match self {
+            Card::Standard(card_number, suit) => {
+                let color = match suit {
+                    Diamonds | Hearts => format!("{}", Fg(Red)),
+                    Clubs | Spades => format!("{}", Fg(White)),
+                };
+                let reset = format!("{}", Fg(Reset));
+                vec![
+                    "┌─────┐".to_string(),
+                    format!("{}│{}   │{}", color, card_number, reset),
+                    format!("{}│  {}  │{}", color, suit, reset),
+                    format!("{}│   {}│{}", color, card_number, reset),
+                    "└─────┘".to_string(),
+                ]
+            }
+            Card::Joker => {
+                let color = format!("{}", Fg(Magenta));
+                let reset = format!("{}", Fg(Reset));
+                vec![
+                    "┌─────┐".to_string(),
+                    format!("{}│     │{}", color, reset),
+                    format!("{}│JOKER│{}", color, reset),
+                    format!("{}│     │{}", color, reset),
+                    "└─────┘".to_string(),
+                ]
+            }
+        }
     }
 }
 
+/// The face-down card back shown in place of a hidden hole card.
+fn hidden_card_lines() -> Vec<String> {
+    let color = format!("{}", Fg(White));
+    let reset = format!("{}", Fg(Reset));
+    vec![
+        "┌─────┐".to_string(),
+        format!("{}│░░░░░│{}", color, reset),
+        format!("{}│░░░░░│{}", color, reset),
+        format!("{}│░░░░░│{}", color, reset),
+        "└─────┘".to_string(),
+    ]
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hand {
     pub cards: Vec<Card>,
+    /// Whether the second card (the dealer's hole card) should be masked
+    /// by `render`/`render_row` and left out of `visible_points`.
+    hole_hidden: bool,
 }
 
 impl Hand {
     pub fn new() -> Hand {
         Hand {
             cards: Vec::with_capacity(11),
+            hole_hidden: false,
         }
     }
 
@@ -101,57 +154,132 @@ impl Hand {
         self.cards.push(card);
     }
 
+    pub fn hide_hole_card(&mut self) {
+        self.hole_hidden = true;
+    }
+
+    pub fn reveal_hole_card(&mut self) {
+        self.hole_hidden = false;
+    }
+
     pub fn points(&self) -> u8 {
+        self.totals().0
+    }
+
+    /// Whether this hand is "soft": at least one ace is still being
+    /// counted as 11 rather than 1.
+    pub fn is_soft(&self) -> bool {
+        self.totals().1
+    }
+
+    /// Same as `points`, but counting only the cards `render` actually
+    /// shows: if the hole card is hidden, it's left out of the total.
+    pub fn visible_points(&self) -> u8 {
+        if self.hole_hidden && self.cards.len() > 1 {
+            let mut visible = Hand::new();
+            visible.cards = self
+                .cards
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != 1)
+                .map(|(_, c)| c.clone())
+                .collect();
+            visible.points()
+        } else {
+            self.points()
+        }
+    }
+
+    /// Sums the hand counting every ace as 11, then downgrades aces to 1
+    /// one at a time until the total is 21 or under (or there are no more
+    /// aces left to downgrade). Returns the final total and whether an
+    /// ace is still counted as 11.
+    fn totals(&self) -> (u8, bool) {
         use CardNumber::*;
-        self.cards.iter().fold(0, |it, Card(n, _)| match n {
-            Ace => {
-                if it + 11 > 21 {
-                    it + 1
+        let mut aces = 0u8;
+        let total: u16 = self.cards.iter().fold(0u16, |it, card| match card {
+            Card::Standard(Ace, _) => {
+                aces += 1;
+                it + 11
+            }
+            Card::Standard(Two, _) => it + 2,
+            Card::Standard(Three, _) => it + 3,
+            Card::Standard(Four, _) => it + 4,
+            Card::Standard(Five, _) => it + 5,
+            Card::Standard(Six, _) => it + 6,
+            Card::Standard(Seven, _) => it + 7,
+            Card::Standard(Eight, _) => it + 8,
+            Card::Standard(Nine, _) => it + 9,
+            Card::Standard(Ten | Jack | Queen | King, _) => it + 10,
+            // Jokers are always filtered out before they reach a blackjack hand.
+            Card::Joker => it,
+        });
+
+        let mut total = total;
+        let mut soft_aces = aces;
+        while total > 21 && soft_aces > 0 {
+            total -= 10;
+            soft_aces -= 1;
+        }
+        (total as u8, soft_aces > 0)
+    }
+
+    /// Lays this hand's cards out side by side as `CARD_HEIGHT` lines of
+    /// boxed card art, masking the hole card with a card back while it's
+    /// hidden. Empty for a hand with no cards yet.
+    pub fn render_row(&self) -> Vec<String> {
+        if self.cards.is_empty() {
+            return Vec::new();
+        }
+
+        let cards: Vec<Vec<String>> = self
+            .cards
+            .iter()
+            .enumerate()
+            .map(|(i, card)| {
+                if self.hole_hidden && i == 1 {
+                    hidden_card_lines()
                 } else {
-                    it + 11
+                    card.render_lines()
                 }
-            }
-            Two => it + 2,
-            Three => it + 3,
-            Four => it + 4,
-            Five => it + 5,
-            Six => it + 6,
-            Seven => it + 7,
-            Eight => it + 8,
-            Nine => it + 9,
-            Ten | Jack | Queen | King => it + 10,
-        })
+            })
+            .collect();
+
+        (0..CARD_HEIGHT)
+            .map(|row| {
+                cards
+                    .iter()
+                    .map(|lines| lines[row].as_str())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
     }
 
     pub fn render(&self, terminal: &mut Terminal, name: &str) {
         terminal.println(format!("{}{}:{}", Fg(LightBlue), name, Fg(Reset)));
-        terminal.println(format!("Cards: {}", self));
+        for line in self.render_row() {
+            terminal.println(line);
+        }
         terminal.println(format!(
             "Points: {}{}{}",
             Fg(Blue),
-            self.points(),
+            self.visible_points(),
             Fg(Reset)
         ));
         terminal.println("");
     }
 }
 
-impl Display for Hand {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.cards
-                .iter()
-                .map(|c| c.to_string())
-                .collect::<Vec<_>>()
-                .join(" "),
-        )
+/// Builds a single 52-card pack, optionally topped up with `NUM_JOKERS`
+/// jokers. Jokers carry no rank or suit, so callers that can't use them
+/// (e.g. blackjack) are expected to filter them out as they're dealt.
+pub fn pack(jokers: WithOrWithoutJokers) -> Vec<Card> {
+    let mut cards: Vec<Card> = Suit::iter()
+        .flat_map(|suit| CardNumber::iter().map(move |number| Card::Standard(number, suit)))
+        .collect();
+    if jokers == WithOrWithoutJokers::With {
+        cards.extend(vec![Card::Joker; NUM_JOKERS]);
     }
-}
-
-pub fn pack() -> Vec<Card> {
-    Suit::iter()
-        .flat_map(|suit| CardNumber::iter().map(move |number| Card(number, suit)))
-        .collect()
+    cards
 }
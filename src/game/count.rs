@@ -0,0 +1,20 @@
+use super::card::{Card, CardNumber};
+
+/// The Hi-Lo system's tag for a single card: low cards are worth more to
+/// the player once they leave the shoe, tens and aces worth less.
+pub fn hi_lo_value(card: &Card) -> i32 {
+    use CardNumber::*;
+    match card {
+        Card::Standard(Two | Three | Four | Five | Six, _) => 1,
+        Card::Standard(Seven | Eight | Nine, _) => 0,
+        Card::Standard(Ten | Jack | Queen | King | Ace, _) => -1,
+        Card::Joker => 0,
+    }
+}
+
+/// The running count scaled by the estimated number of decks left in the
+/// shoe, rounded toward zero as is conventional for the true count.
+pub fn true_count(running_count: i32, cards_remaining: usize) -> i32 {
+    let decks_remaining = (cards_remaining as f32 / 52.0).max(1.0 / 52.0);
+    (running_count as f32 / decks_remaining).trunc() as i32
+}
@@ -0,0 +1,111 @@
+use std::fs::File;
+use std::io::{self, stdin, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::card::{Card, Hand};
+use super::State;
+
+/// Who a dealt card went to: the dealer, or one of the player's (possibly
+/// split) hands, indexed the same way as `Game::player_hands`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Recipient {
+    Dealer,
+    Player(usize),
+}
+
+/// A single entry in a recorded session: either a card leaving the shoe, or
+/// the game reaching a new state. `StateChanged` carries a full snapshot of
+/// both hands rather than just the new `State` so a recorded session can be
+/// replayed, or its hands fed straight to the strategy advisor, without
+/// having to replay every `CardDealt` event first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LogEvent {
+    CardDealt { recipient: Recipient, card: Card },
+    StateChanged {
+        state: State,
+        dealer: Hand,
+        player_hands: Vec<Hand>,
+    },
+}
+
+/// Buffers the events of a session in memory and writes them out as
+/// newline-delimited JSON when told to, gated behind a `--record <path>`
+/// flag parsed in `main`. Does nothing when no path was given.
+pub struct SessionRecorder {
+    path: Option<PathBuf>,
+    events: Vec<LogEvent>,
+}
+
+impl SessionRecorder {
+    pub fn new(path: Option<PathBuf>) -> SessionRecorder {
+        SessionRecorder {
+            path,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, event: LogEvent) {
+        if self.path.is_some() {
+            self.events.push(event);
+        }
+    }
+
+    /// Writes every recorded event as newline-delimited JSON to `path`.
+    /// Called when the owning `Game` is dropped; a no-op if recording
+    /// wasn't enabled.
+    pub fn flush(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let mut file = File::create(path).expect("failed to create session log file");
+        for event in &self.events {
+            let line = serde_json::to_string(event).expect("log event should serialize");
+            writeln!(file, "{}", line).expect("failed to write session log");
+        }
+    }
+}
+
+/// Reads a session recorded by `SessionRecorder` and re-renders it one
+/// event at a time, pausing for Enter between steps so it can be reviewed
+/// offline without a live terminal.
+pub fn replay(path: &Path) -> io::Result<()> {
+    let file = File::open(path)?;
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        let event: LogEvent = serde_json::from_str(&line)
+            .unwrap_or_else(|err| panic!("malformed log event on line {}: {}", i + 1, err));
+        print_event(&event);
+        print!("[Enter] Next ");
+        io::stdout().flush()?;
+        stdin().read_line(&mut String::new())?;
+    }
+    Ok(())
+}
+
+fn print_event(event: &LogEvent) {
+    match event {
+        LogEvent::CardDealt { recipient, card } => match recipient {
+            Recipient::Dealer => println!("Dealer is dealt {:?}", card),
+            Recipient::Player(i) => println!("Hand {} is dealt {:?}", i + 1, card),
+        },
+        LogEvent::StateChanged {
+            state,
+            dealer,
+            player_hands,
+        } => {
+            println!("--- {:?} ---", state);
+            println!("Dealer:");
+            for line in dealer.render_row() {
+                println!("{}", line);
+            }
+            for (i, hand) in player_hands.iter().enumerate() {
+                println!("Hand {}:", i + 1);
+                for line in hand.render_row() {
+                    println!("{}", line);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use rand::seq::SliceRandom;
+
+use super::card::{pack, Card, WithOrWithoutJokers};
+
+pub const MIN_DECKS: u8 = 1;
+pub const MAX_DECKS: u8 = 8;
+
+/// A continuous-play shoe: several packs shuffled together, dealt from
+/// until a cut card (placed `penetration` of the way through the shoe) is
+/// reached, at which point the discard pile is shuffled back in rather
+/// than the shoe being rebuilt every round.
+pub struct Shoe {
+    decks: u8,
+    penetration: f32,
+    jokers: WithOrWithoutJokers,
+    cards: Vec<Card>,
+    discard: Vec<Card>,
+    cut_card: usize,
+}
+
+impl Shoe {
+    pub fn new(decks: u8, penetration: f32, jokers: WithOrWithoutJokers) -> Shoe {
+        let mut shoe = Shoe {
+            decks: decks.clamp(MIN_DECKS, MAX_DECKS),
+            penetration: penetration.clamp(0.0, 1.0),
+            jokers,
+            cards: Vec::new(),
+            discard: Vec::new(),
+            cut_card: 0,
+        };
+        shoe.fill_and_shuffle();
+        shoe
+    }
+
+    fn fill_and_shuffle(&mut self) {
+        self.cards = (0..self.decks).flat_map(|_| pack(self.jokers)).collect();
+        self.discard.clear();
+        self.shuffle_and_place_cut_card();
+    }
+
+    /// Shuffles whatever is currently in `cards` and recomputes where the
+    /// cut card falls, without touching `discard`. Shared by the initial
+    /// fill and by `reshuffle_if_needed`, which reshuffles the existing
+    /// cards rather than building a fresh shoe.
+    fn shuffle_and_place_cut_card(&mut self) {
+        self.cards.shuffle(&mut rand::thread_rng());
+        let total = self.cards.len();
+        self.cut_card = total - (total as f32 * self.penetration).round() as usize;
+    }
+
+    /// Whether the shoe has been dealt down to (or past) the cut card.
+    pub fn past_cut_card(&self) -> bool {
+        self.cards.len() <= self.cut_card
+    }
+
+    pub fn deal(&mut self) -> Card {
+        self.cards.pop().expect("shoe ran out of cards")
+    }
+
+    pub fn discard(&mut self, cards: &mut Vec<Card>) {
+        self.discard.append(cards);
+    }
+
+    pub fn discard_one(&mut self, card: Card) {
+        self.discard.push(card);
+    }
+
+    /// Shuffles the discard pile back into the shoe, but only once the cut
+    /// card has been reached; otherwise this is a no-op. Returns whether a
+    /// reshuffle happened, so callers can reset anything that tracks the
+    /// shoe's contents (e.g. a running card count).
+    pub fn reshuffle_if_needed(&mut self) -> bool {
+        if self.past_cut_card() {
+            self.cards.append(&mut self.discard);
+            self.shuffle_and_place_cut_card();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn cards_remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn decks_remaining(&self) -> f32 {
+        self.cards.len() as f32 / 52.0
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt::Display;
+
+use super::card::{Card, CardNumber};
+use super::Hand;
+
+/// The statistically optimal move for a given hand, independent of the
+/// game's own `Choice`: the advisor only ever renders a hint, it never
+/// drives the state machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Advice {
+    Hit,
+    Stand,
+    Double,
+    Split,
+}
+
+impl Display for Advice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Advice::*;
+        match self {
+            Hit => write!(f, "Hit"),
+            Stand => write!(f, "Stand"),
+            Double => write!(f, "Double"),
+            Split => write!(f, "Split"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DealerUpcard {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Ace,
+}
+
+impl DealerUpcard {
+    fn of(card: &Card) -> DealerUpcard {
+        use CardNumber as N;
+        use DealerUpcard as D;
+        match card {
+            Card::Standard(N::Ace, _) => D::Ace,
+            Card::Standard(N::Two, _) => D::Two,
+            Card::Standard(N::Three, _) => D::Three,
+            Card::Standard(N::Four, _) => D::Four,
+            Card::Standard(N::Five, _) => D::Five,
+            Card::Standard(N::Six, _) => D::Six,
+            Card::Standard(N::Seven, _) => D::Seven,
+            Card::Standard(N::Eight, _) => D::Eight,
+            Card::Standard(N::Nine, _) => D::Nine,
+            Card::Standard(N::Ten | N::Jack | N::Queen | N::King, _) => D::Ten,
+            Card::Joker => unreachable!("the dealer never shows a joker in blackjack"),
+        }
+    }
+
+    fn rank(self) -> u8 {
+        use DealerUpcard::*;
+        match self {
+            Two => 2,
+            Three => 3,
+            Four => 4,
+            Five => 5,
+            Six => 6,
+            Seven => 7,
+            Eight => 8,
+            Nine => 9,
+            Ten => 10,
+            Ace => 11,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PairRank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Ace,
+}
+
+/// Whether `hand` is a splittable pair: exactly two cards of the same
+/// rank (treating ten, jack, queen and king as equivalent).
+pub fn is_pair(hand: &Hand) -> bool {
+    pair_rank(hand).is_some()
+}
+
+fn pair_rank(hand: &Hand) -> Option<PairRank> {
+    use CardNumber as N;
+    use PairRank as P;
+    let group = |n: CardNumber| match n {
+        N::Ace => P::Ace,
+        N::Two => P::Two,
+        N::Three => P::Three,
+        N::Four => P::Four,
+        N::Five => P::Five,
+        N::Six => P::Six,
+        N::Seven => P::Seven,
+        N::Eight => P::Eight,
+        N::Nine => P::Nine,
+        N::Ten | N::Jack | N::Queen | N::King => P::Ten,
+    };
+    match &hand.cards[..] {
+        [Card::Standard(a, _), Card::Standard(b, _)] if group(*a) == group(*b) => {
+            Some(group(*a))
+        }
+        _ => None,
+    }
+}
+
+/// Basic-strategy action for a hard total (no ace counted as 11).
+fn hard_advice(total: u8, dealer: DealerUpcard) -> Advice {
+    use Advice::*;
+    let d = dealer.rank();
+    match total {
+        0..=8 => Hit,
+        9 => {
+            if (3..=6).contains(&d) {
+                Double
+            } else {
+                Hit
+            }
+        }
+        10 => {
+            if (2..=9).contains(&d) {
+                Double
+            } else {
+                Hit
+            }
+        }
+        11 => {
+            if d != 11 {
+                Double
+            } else {
+                Hit
+            }
+        }
+        12 => {
+            if (4..=6).contains(&d) {
+                Stand
+            } else {
+                Hit
+            }
+        }
+        13..=16 => {
+            if (2..=6).contains(&d) {
+                Stand
+            } else {
+                Hit
+            }
+        }
+        _ => Stand,
+    }
+}
+
+/// Basic-strategy action for a soft total (an ace still counted as 11).
+/// `total` is the full soft total, e.g. 13 for A+2.
+fn soft_advice(total: u8, dealer: DealerUpcard) -> Advice {
+    use Advice::*;
+    let d = dealer.rank();
+    match total {
+        // A pair of aces that can't be split (e.g. unaffordable) falls
+        // back here as a soft 12; it can't bust, so it's always a Hit.
+        12 => Hit,
+        13 | 14 => {
+            if (5..=6).contains(&d) {
+                Double
+            } else {
+                Hit
+            }
+        }
+        15 | 16 => {
+            if (4..=6).contains(&d) {
+                Double
+            } else {
+                Hit
+            }
+        }
+        17 => {
+            if (3..=6).contains(&d) {
+                Double
+            } else {
+                Hit
+            }
+        }
+        18 => match d {
+            2 | 7 | 8 => Stand,
+            3..=6 => Double,
+            _ => Hit,
+        },
+        19 => {
+            if d == 6 {
+                Double
+            } else {
+                Stand
+            }
+        }
+        _ => Stand,
+    }
+}
+
+fn pair_advice(rank: PairRank, dealer: DealerUpcard) -> Advice {
+    use Advice::*;
+    use PairRank::*;
+    let d = dealer.rank();
+    match rank {
+        Two | Three => {
+            if (2..=7).contains(&d) {
+                Split
+            } else {
+                Hit
+            }
+        }
+        Four => {
+            if (5..=6).contains(&d) {
+                Split
+            } else {
+                Hit
+            }
+        }
+        Five => {
+            if (2..=9).contains(&d) {
+                Double
+            } else {
+                Hit
+            }
+        }
+        Six => {
+            if (2..=6).contains(&d) {
+                Split
+            } else {
+                Hit
+            }
+        }
+        Seven => {
+            if (2..=7).contains(&d) {
+                Split
+            } else {
+                Hit
+            }
+        }
+        Eight => Split,
+        Nine => {
+            if (2..=6).contains(&d) || d == 8 || d == 9 {
+                Split
+            } else {
+                Stand
+            }
+        }
+        Ten => Stand,
+        Ace => Split,
+    }
+}
+
+/// Computes the basic-strategy action for `player` against `dealer_upcard`,
+/// falling back to the closest legal move when double/split aren't
+/// currently available: `Double` becomes `Hit`, and a pair that can't be
+/// split is scored by the hard/soft table instead.
+pub fn decide(player: &Hand, dealer_upcard: &Card, can_double: bool, can_split: bool) -> Advice {
+    let dealer = DealerUpcard::of(dealer_upcard);
+
+    let advice = match pair_rank(player) {
+        Some(rank) if can_split => pair_advice(rank, dealer),
+        _ if player.is_soft() => soft_advice(player.points(), dealer),
+        _ => hard_advice(player.points(), dealer),
+    };
+
+    if advice == Advice::Double && !can_double {
+        Advice::Hit
+    } else {
+        advice
+    }
+}
@@ -1,12 +1,38 @@
-use std::io::stdin;
+use std::{env, io::stdin, path::{Path, PathBuf}};
 use termion::{event::Key, input::TermRead};
 
 pub mod game;
 
+use game::card::WithOrWithoutJokers;
 use game::*;
 
 fn main() {
-    let mut game = Game::new();
+    let args: Vec<String> = env::args().collect();
+
+    if let Some(path) = flag_value(&args, "--replay") {
+        return game::log::replay(Path::new(&path)).expect("failed to replay session log");
+    }
+
+    let defaults = GameOptions::default();
+    let options = GameOptions {
+        decks: flag_value(&args, "--decks")
+            .map(|v| v.parse().expect("--decks expects a number"))
+            .unwrap_or(defaults.decks),
+        penetration: flag_value(&args, "--penetration")
+            .map(|v| v.parse().expect("--penetration expects a number"))
+            .unwrap_or(defaults.penetration),
+        jokers: if flag_present(&args, "--jokers") {
+            WithOrWithoutJokers::With
+        } else {
+            defaults.jokers
+        },
+        show_strategy_hint: !flag_present(&args, "--no-hint"),
+        trainer_mode: flag_present(&args, "--trainer"),
+        record_path: flag_value(&args, "--record").map(PathBuf::from),
+        ..defaults
+    };
+
+    let mut game = Game::with_options(options);
 
     let stdin = stdin();
 
@@ -23,3 +49,18 @@ fn main() {
         game.render();
     }
 }
+
+/// Looks up the value following `flag` in the raw `argv`, e.g. the path
+/// after `--record` or `--replay`.
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .cloned()
+}
+
+/// Whether a standalone, valueless flag (e.g. `--trainer`, `--no-hint`,
+/// `--jokers`) was passed.
+fn flag_present(args: &[String], flag: &str) -> bool {
+    args.iter().any(|a| a == flag)
+}